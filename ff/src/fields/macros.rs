@@ -248,6 +248,557 @@ macro_rules! impl_Fp {
                     self.0.sub_noborrow(&P::MODULUS);
                 }
             }
+
+            /// Given a slice of field elements, replace each with its multiplicative inverse,
+            /// leaving any zero elements untouched.
+            ///
+            /// This uses Montgomery's trick: the whole slice is inverted with a single call to
+            /// [`inverse`](Field::inverse) plus three multiplications per element, rather than
+            /// one `inverse` per element.
+            pub fn batch_inverse(v: &mut [Self]) {
+                Self::batch_inverse_and_mul(v, &Self::one());
+            }
+
+            /// Like [`batch_inverse`](Self::batch_inverse), but multiplies every inverted
+            /// element by `coeff` afterwards.
+            pub fn batch_inverse_and_mul(v: &mut [Self], coeff: &Self) {
+                // Walk forward, accumulating the running product of the non-zero elements seen
+                // so far and recording it after each one; zero elements are simply skipped.
+                let mut prod = ark_std::vec::Vec::with_capacity(v.len());
+                let mut acc = Self::one();
+                for x in v.iter().filter(|x| !x.is_zero()) {
+                    acc *= x;
+                    prod.push(acc);
+                }
+
+                // Invert the accumulated product with a single call to `inverse`; it is
+                // guaranteed to be non-zero since every zero element was skipped above.
+                let mut acc_inv = acc.inverse().expect("product of non-zero field elements is non-zero");
+                acc_inv *= coeff;
+
+                // Walk backward, peeling off one factor at a time: at each non-zero element,
+                // its inverse is `acc_inv * prod[i - 1]` (or `acc_inv` for the first non-zero
+                // element), after which `acc_inv` is updated to remove that element's factor.
+                for (x, prod_before) in v
+                    .iter_mut()
+                    .filter(|x| !x.is_zero())
+                    .rev()
+                    .zip(prod.into_iter().rev().skip(1).chain(Some(Self::one())))
+                {
+                    let inv = acc_inv * &prod_before;
+                    acc_inv *= &*x;
+                    *x = inv;
+                }
+            }
+
+            /// Exercises [`batch_inverse`](Self::batch_inverse) on the empty slice, a slice
+            /// containing only zeros, a mix of zero and non-zero elements, and checks
+            /// [`batch_inverse_and_mul`](Self::batch_inverse_and_mul) against inverting then
+            /// multiplying one element at a time. Concrete field crates should call this from a
+            /// `#[test]` fn once a concrete `P` is in scope, e.g.: `#[test] fn
+            /// batch_inverse_matches_per_element() { batch_inverse_test_template::<FrParameters>() }`
+            #[cfg(test)]
+            pub(crate) fn batch_inverse_test_template<P: $FpParameters>() {
+                let mut empty: [$Fp<P>; 0] = [];
+                $Fp::<P>::batch_inverse(&mut empty);
+
+                let zero = $Fp::<P>::zero();
+                let mut zeros = [zero, zero];
+                $Fp::<P>::batch_inverse(&mut zeros);
+                assert_eq!(zeros, [zero, zero]);
+
+                let one = $Fp::<P>::one();
+                let two = one + &one;
+                let three = two + &one;
+                let mut mixed = [one, zero, two, three];
+                let expected = [
+                    one.inverse().unwrap(),
+                    zero,
+                    two.inverse().unwrap(),
+                    three.inverse().unwrap(),
+                ];
+                $Fp::<P>::batch_inverse(&mut mixed);
+                assert_eq!(mixed, expected);
+
+                let coeff = two;
+                let mut scaled = [one, two, three];
+                $Fp::<P>::batch_inverse_and_mul(&mut scaled, &coeff);
+                assert_eq!(
+                    scaled,
+                    [
+                        one.inverse().unwrap() * &coeff,
+                        two.inverse().unwrap() * &coeff,
+                        three.inverse().unwrap() * &coeff,
+                    ]
+                );
+            }
+
+            /// Computes `self^{-1}` using the Bernstein–Yang "safegcd" algorithm, running a
+            /// fixed number of `divstep`s whose control flow does not depend on `self`.
+            ///
+            /// Unlike [`inverse`](Field::inverse), which runs the binary extended Euclidean
+            /// algorithm for a number of iterations that depends on the value being inverted,
+            /// this method's number and kind of limb operations depend only on the modulus,
+            /// which matters when `self` is secret key material.
+            #[cfg(feature = "ct-inverse")]
+            pub fn inverse_ct(&self) -> Option<Self> {
+                // Whether the final result is `None` is decided only after the divstep loop
+                // below has run its fixed number of iterations: branching here and skipping the
+                // loop for a zero input would make whether `self` is zero observable via
+                // timing, exactly what this method exists to prevent. For `self == 0`, `g`
+                // starts at zero and every divstep's "is `g` odd" test is false, so the loop
+                // deterministically leaves `f = MODULUS`, `c = 0`, matching `f_neg = false`.
+                let is_zero = self.is_zero();
+
+                // `f` and `g` track `MODULUS` and `self` as they range over the signed
+                // integers, so each carries its sign alongside its (unsigned) magnitude.
+                let mut f_neg = false;
+                let mut f = P::MODULUS;
+                let mut g_neg = false;
+                let mut g = self.0;
+                let mut delta: i64 = 1;
+
+                // `c` and `d` are the Bezout coefficients of `self` for `f` and `g`
+                // respectively, i.e. the invariants `f = c * self (mod MODULUS)` and
+                // `g = d * self (mod MODULUS)` are maintained throughout. As in `inverse`
+                // below, `d` starts at `R2` rather than `1` so that the final result is
+                // already in Montgomery form, without a separate rescaling step at the end.
+                let mut c = Self::zero();
+                let mut d = $Fp::<P>(P::R2, PhantomData);
+
+                // `2 * MODULUS_BITS - 1` divsteps always suffice to drive `g` to zero. Every
+                // divstep below runs both of its possible outcomes and blends them with a
+                // bitmask derived from `swap`/`g_odd`, rather than branching on them, so the
+                // sequence of operations executed is the same (`num_iterations` of them) and
+                // data-independent of `self`.
+                let num_iterations = 2 * P::MODULUS_BITS - 1;
+                for _ in 0..num_iterations {
+                    let g_odd = !g.is_even();
+                    let g_odd_mask = Self::ct_mask(g_odd);
+
+                    // `delta > 0`, computed from the sign bit and a zero check rather than `>`.
+                    let delta_negative = ((delta as u64) >> 63) & 1 == 1;
+                    let delta_positive_mask = !Self::ct_mask(delta_negative) & !Self::ct_mask(delta == 0);
+                    let swap_mask = delta_positive_mask & g_odd_mask;
+
+                    delta = Self::ct_select_i64(swap_mask, -delta, delta + 1);
+
+                    // (f, g) <- (g, (g - f) / 2) when swapping, (f, (g + f) / 2) when not
+                    // swapping but `g` is odd, and (f, g) unchanged otherwise — computed by
+                    // evaluating every outcome and selecting, never branching on `swap`/`g_odd`.
+                    let (sub_g_neg, sub_g) = Self::signed_sub(g_neg, g, f_neg, f);
+                    let (add_g_neg, add_g) = Self::signed_add(g_neg, g, f_neg, f);
+                    let keep_g_neg = Self::ct_select_bool(g_odd_mask, add_g_neg, g_neg);
+                    let keep_g = Self::ct_select_big(g_odd_mask, add_g, g);
+
+                    let new_f_neg = Self::ct_select_bool(swap_mask, g_neg, f_neg);
+                    let new_f = Self::ct_select_big(swap_mask, g, f);
+                    let new_g_neg = Self::ct_select_bool(swap_mask, sub_g_neg, keep_g_neg);
+                    let new_g = Self::ct_select_big(swap_mask, sub_g, keep_g);
+                    f_neg = new_f_neg;
+                    f = new_f;
+                    g_neg = new_g_neg;
+                    g = new_g;
+
+                    // Coefficient bookkeeping for the same three outcomes: (c, d) <- (d, d - c)
+                    // when swapping, (c, d + c) when not swapping but `g` is odd, (c, d)
+                    // otherwise.
+                    let d_minus_c = Self::ct_sub_fp(&d, &c);
+                    let d_plus_c = Self::ct_add_fp(&d, &c);
+                    let new_c = Self::ct_select_fp(swap_mask, d, c);
+                    let keep_d = Self::ct_select_fp(g_odd_mask, d_plus_c, d);
+                    let new_d = Self::ct_select_fp(swap_mask, d_minus_c, keep_d);
+                    c = new_c;
+                    d = new_d;
+
+                    g.div2();
+                    d = Self::half(&d);
+                }
+
+                // `f` now holds `±gcd(MODULUS, self) = ±1`, so `c`, its paired coefficient,
+                // satisfies `c * self = ±1 (mod MODULUS)` — unless `self` was zero, in which
+                // case the loop above left `c = 0` and the result is `None`.
+                let negated = -c;
+                let result = Self::ct_select_fp(Self::ct_mask(f_neg), negated, c);
+                if is_zero {
+                    None
+                } else {
+                    Some(result)
+                }
+            }
+
+            /// Halves a field element modulo `P::MODULUS`, without branching on the parity of
+            /// `x`: both outcomes (divide directly, or add the modulus back in first) are
+            /// computed and blended with a mask, since `x`'s parity depends on `self` in
+            /// [`inverse_ct`].
+            #[cfg(feature = "ct-inverse")]
+            fn half(x: &Self) -> Self {
+                let odd_mask = Self::ct_mask(!x.0.is_even());
+
+                let mut shifted_even = x.0;
+                shifted_even.div2();
+
+                let mut shifted_odd = x.0;
+                shifted_odd.add_nocarry(&P::MODULUS);
+                shifted_odd.div2();
+
+                Self($Fp::<P>::ct_select_big(odd_mask, shifted_odd, shifted_even), PhantomData)
+            }
+
+            /// Adds two sign-and-magnitude integers, returning the sign-and-magnitude of the
+            /// (possibly negative) result. Both the same-sign and differing-sign cases, and
+            /// both orderings of the differing-sign case, are computed unconditionally and
+            /// blended with masks rather than selected via `if`/`else`, since the signs and
+            /// magnitudes are derived from `self` in [`inverse_ct`].
+            #[cfg(feature = "ct-inverse")]
+            fn signed_add(a_neg: bool, a: $BigIntegerType, b_neg: bool, b: $BigIntegerType) -> (bool, $BigIntegerType) {
+                let same_sign_mask = Self::ct_mask(a_neg == b_neg);
+
+                let mut sum = a;
+                sum.add_nocarry(&b);
+
+                let (diff_a_b, borrow_a_b) = Self::ct_sub_with_borrow(&a, &b);
+                let (diff_b_a, _) = Self::ct_sub_with_borrow(&b, &a);
+                let a_ge_b_mask = Self::ct_mask(borrow_a_b == 0);
+
+                let diff_mag = Self::ct_select_big(a_ge_b_mask, diff_a_b, diff_b_a);
+                let diff_neg = Self::ct_select_bool(a_ge_b_mask, a_neg, b_neg);
+
+                let mag = Self::ct_select_big(same_sign_mask, sum, diff_mag);
+                let neg = Self::ct_select_bool(same_sign_mask, a_neg, diff_neg);
+                (neg, mag)
+            }
+
+            /// `a - b` for sign-and-magnitude integers, implemented as `a + (-b)`.
+            #[cfg(feature = "ct-inverse")]
+            fn signed_sub(a_neg: bool, a: $BigIntegerType, b_neg: bool, b: $BigIntegerType) -> (bool, $BigIntegerType) {
+                Self::signed_add(a_neg, a, !b_neg, b)
+            }
+
+            /// Returns an all-one-bits mask when `bit` is true, all-zero-bits otherwise, for
+            /// use with the `ct_select_*` helpers below.
+            #[cfg(feature = "ct-inverse")]
+            #[inline]
+            fn ct_mask(bit: bool) -> u64 {
+                0u64.wrapping_sub(bit as u64)
+            }
+
+            #[cfg(feature = "ct-inverse")]
+            #[inline]
+            fn ct_select_u64(mask: u64, a: u64, b: u64) -> u64 {
+                b ^ (mask & (a ^ b))
+            }
+
+            #[cfg(feature = "ct-inverse")]
+            #[inline]
+            fn ct_select_bool(mask: u64, a: bool, b: bool) -> bool {
+                Self::ct_select_u64(mask, a as u64, b as u64) != 0
+            }
+
+            #[cfg(feature = "ct-inverse")]
+            #[inline]
+            fn ct_select_i64(mask: u64, a: i64, b: i64) -> i64 {
+                Self::ct_select_u64(mask, a as u64, b as u64) as i64
+            }
+
+            #[cfg(feature = "ct-inverse")]
+            #[ark_ff_asm::unroll_for_loops]
+            fn ct_select_big(mask: u64, a: $BigIntegerType, b: $BigIntegerType) -> $BigIntegerType {
+                let mut out = [0u64; $limbs];
+                for i in 0..$limbs {
+                    out[i] = Self::ct_select_u64(mask, a.0[i], b.0[i]);
+                }
+                $BigInteger(out)
+            }
+
+            #[cfg(feature = "ct-inverse")]
+            #[inline]
+            fn ct_select_fp(mask: u64, a: Self, b: Self) -> Self {
+                Self(Self::ct_select_big(mask, a.0, b.0), PhantomData)
+            }
+
+            /// `a - b` without branching on the sign of the result: returns the wrapped
+            /// difference together with the final borrow (0 or 1), so callers can blend on
+            /// `borrow` rather than comparing `a`/`b` directly.
+            #[cfg(feature = "ct-inverse")]
+            #[ark_ff_asm::unroll_for_loops]
+            #[allow(unused_assignments)]
+            fn ct_sub_with_borrow(a: &$BigIntegerType, b: &$BigIntegerType) -> ($BigIntegerType, u64) {
+                let mut out = [0u64; $limbs];
+                let mut borrow = 0;
+                for i in 0..$limbs {
+                    out[i] = sbb!(a.0[i], b.0[i], &mut borrow);
+                }
+                ($BigInteger(out), borrow)
+            }
+
+            /// `a + b mod P::MODULUS` for Montgomery-form field elements, blending the
+            /// conditional final subtraction with a mask instead of branching on it, since `a`
+            /// and `b` are derived from `self` in [`inverse_ct`].
+            #[cfg(feature = "ct-inverse")]
+            fn ct_add_fp(a: &Self, b: &Self) -> Self {
+                let mut sum = a.0;
+                sum.add_nocarry(&b.0);
+                let (reduced, borrow) = Self::ct_sub_with_borrow(&sum, &P::MODULUS);
+                let mag = Self::ct_select_big(Self::ct_mask(borrow == 0), reduced, sum);
+                Self(mag, PhantomData)
+            }
+
+            /// `a - b mod P::MODULUS` for Montgomery-form field elements, blending the
+            /// conditional modulus add-back with a mask instead of branching on it, since `a`
+            /// and `b` are derived from `self` in [`inverse_ct`].
+            #[cfg(feature = "ct-inverse")]
+            fn ct_sub_fp(a: &Self, b: &Self) -> Self {
+                let (diff, borrow) = Self::ct_sub_with_borrow(&a.0, &b.0);
+                let mut added = diff;
+                added.add_nocarry(&P::MODULUS);
+                let mag = Self::ct_select_big(Self::ct_mask(borrow != 0), added, diff);
+                Self(mag, PhantomData)
+            }
+
+            /// One step of a dual `adcx`/`adox` carry-chain accumulation: multiplies `a * b`
+            /// via the stable `_mulx_u64` intrinsic, adds the low word into `acc_lo` along the
+            /// `adcx` chain (carried through `carry_lo`), and the high word into `acc_hi` along
+            /// an independent `adox` chain (carried through `carry_hi`). `asm!` blocks don't
+            /// preserve flags across calls, so each incoming carry is restored into CF/OF with
+            /// an `add` that overflows iff the carry bit is set (`0xff` for CF, `0x7f` for OF,
+            /// the standard 8-bit-register trick), and the outgoing carry is read back out with
+            /// `setc`/`seto` immediately after.
+            #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+            #[target_feature(enable = "bmi2,adx")]
+            #[inline]
+            unsafe fn mulx_adcx_adox(
+                a: u64,
+                b: u64,
+                acc_lo: u64,
+                carry_lo: u8,
+                acc_hi: u64,
+                carry_hi: u8,
+            ) -> (u64, u8, u64, u8) {
+                use core::arch::x86_64::_mulx_u64;
+
+                let mut hi = 0u64;
+                let lo = _mulx_u64(a, b, &mut hi);
+
+                let mut sum_lo = acc_lo;
+                let mut cl = carry_lo;
+                core::arch::asm!(
+                    "add {cl}, 0xff",
+                    "adcx {sum}, {lo}",
+                    "setc {cl}",
+                    sum = inout(reg) sum_lo,
+                    lo = in(reg) lo,
+                    cl = inout(reg_byte) cl,
+                    options(nomem, nostack),
+                );
+
+                let mut sum_hi = acc_hi;
+                let mut ch = carry_hi;
+                core::arch::asm!(
+                    "add {ch}, 0x7f",
+                    "adox {sum}, {hi}",
+                    "seto {ch}",
+                    sum = inout(reg) sum_hi,
+                    hi = in(reg) hi,
+                    ch = inout(reg_byte) ch,
+                    options(nomem, nostack),
+                );
+
+                (sum_lo, cl, sum_hi, ch)
+            }
+
+            /// CIOS Montgomery multiplication using `mulx`/`adcx`/`adox` for each row: the
+            /// low word of every `mulx` product is folded into `r[j+i]` along one `adcx`
+            /// carry chain while the high word is folded into `r[j+i+1]` along an independent
+            /// `adox` carry chain, so the two 64-bit accumulations run without waiting on each
+            /// other's carry-out. The Montgomery reduction pass below it has the same shape,
+            /// accumulating `k * modulus[j]` instead of `self[i] * other[j]`. Dispatched to at
+            /// runtime (see `MulAssign::mul_assign` below) only when the CPU actually supports
+            /// BMI2 and ADX, and only for the limb counts validated against the portable path
+            /// above (4 and 6); other sizes use [`mul_assign_portable`].
+            #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+            #[target_feature(enable = "bmi2,adx")]
+            unsafe fn mul_assign_adx(&mut self, other: &Self) {
+                let modulus = P::MODULUS;
+                let inv = P::INV;
+                let mut r = [0u64; $limbs * 2];
+
+                for i in 0..$limbs {
+                    let mut carry_lo: u8 = 0;
+                    let mut carry_hi: u8 = 0;
+                    for j in 0..$limbs {
+                        let (new_lo, c_lo, new_hi, c_hi) = Self::mulx_adcx_adox(
+                            self.0.0[i], other.0.0[j], r[j + i], carry_lo, r[j + i + 1], carry_hi,
+                        );
+                        r[j + i] = new_lo;
+                        r[j + i + 1] = new_hi;
+                        carry_lo = c_lo;
+                        carry_hi = c_hi;
+                    }
+                    r[$limbs + i] = r[$limbs + i]
+                        .wrapping_add(carry_lo as u64)
+                        .wrapping_add(carry_hi as u64);
+                }
+
+                let mut carry2 = 0u64;
+                for i in 0..$limbs {
+                    let k = r[i].wrapping_mul(inv);
+                    let mut carry_lo: u8 = 0;
+                    let mut carry_hi: u8 = 0;
+                    for j in 0..$limbs {
+                        let (new_lo, c_lo, new_hi, c_hi) = Self::mulx_adcx_adox(
+                            k, modulus.0[j], r[j + i], carry_lo, r[j + i + 1], carry_hi,
+                        );
+                        r[j + i] = new_lo;
+                        r[j + i + 1] = new_hi;
+                        carry_lo = c_lo;
+                        carry_hi = c_hi;
+                    }
+                    // `carry_lo` belongs to `r[$limbs + i]`; `carry_hi` came out of
+                    // `r[$limbs + i]` itself, so it belongs one limb higher, with `carry2`.
+                    let combined = r[$limbs + i] as u128 + carry2 as u128 + carry_lo as u128;
+                    r[$limbs + i] = combined as u64;
+                    carry2 = (combined >> 64) as u64 + carry_hi as u64;
+                }
+
+                let mut out = [0u64; $limbs];
+                out.copy_from_slice(&r[$limbs..$limbs * 2]);
+                self.0 = $BigInteger(out);
+                self.reduce();
+            }
+
+            /// Falls back to the same Montgomery multiplication as the portable path, for the
+            /// limb counts not yet wired up to [`mul_assign_adx`] or for CPUs [`mul_assign_adx`]
+            /// was not dispatched to at runtime.
+            #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+            fn mul_assign_portable(&mut self, other: &Self) {
+                *self = (*self).mul_without_reduce(other, P::MODULUS, P::INV).const_reduce(P::MODULUS);
+            }
+
+            /// Cross-checks [`mul_assign_adx`](Self::mul_assign_adx) against
+            /// [`mul_assign_portable`](Self::mul_assign_portable) over random inputs, skipping
+            /// itself on CPUs lacking BMI2/ADX. Concrete field crates with a 4- or 6-limb
+            /// representation should call this from a `#[test]` fn once a concrete `P` is in
+            /// scope, e.g.: `#[test] fn mul_assign_adx_matches_portable() {
+            /// mul_assign_adx_test_template::<FrParameters>() }`
+            #[cfg(all(test, feature = "asm", feature = "std", target_arch = "x86_64"))]
+            pub(crate) fn mul_assign_adx_test_template<P: $FpParameters>() {
+                if !(std::is_x86_feature_detected!("bmi2") && std::is_x86_feature_detected!("adx")) {
+                    return;
+                }
+
+                let mut rng = ark_std::test_rng();
+                for _ in 0..100 {
+                    let a = <$Fp<P> as ark_std::UniformRand>::rand(&mut rng);
+                    let b = <$Fp<P> as ark_std::UniformRand>::rand(&mut rng);
+
+                    let mut via_adx = a;
+                    unsafe { via_adx.mul_assign_adx(&b) };
+
+                    let mut via_portable = a;
+                    via_portable.mul_assign_portable(&b);
+
+                    assert_eq!(via_adx, via_portable, "adx and portable Montgomery multiply disagree");
+                }
+            }
+
+            /// Interprets `s` as a field element written in `radix` (2 to 16, inclusive),
+            /// generalizing the strict decimal `FromStr` impl below (which is just
+            /// `from_str_radix(s, 10)`) to bases other than 10 — in particular base 16, the
+            /// form most other curve libraries use for constants and test vectors.
+            pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ()> {
+                if !(2..=16).contains(&radix) {
+                    return Err(());
+                }
+
+                if s.is_empty() {
+                    return Err(());
+                }
+
+                if s == "0" {
+                    return Ok(Self::zero());
+                }
+
+                let mut res = Self::zero();
+                let base = Self::from(radix as u64);
+                let mut first_digit = true;
+
+                for c in s.chars() {
+                    match c.to_digit(radix) {
+                        Some(d) => {
+                            if first_digit {
+                                if d == 0 {
+                                    return Err(());
+                                }
+                                first_digit = false;
+                            }
+
+                            res.mul_assign(&base);
+                            res.add_assign(&Self::from(u64::from(d)));
+                        },
+                        None => {
+                            return Err(());
+                        },
+                    }
+                }
+
+                if !res.is_valid() {
+                    Err(())
+                } else {
+                    Ok(res)
+                }
+            }
+
+            /// Exercises [`from_str_radix`](Self::from_str_radix) (decimal and hex, including
+            /// the rejected forms: empty string, leading zero, out-of-range digit, out-of-range
+            /// radix) and round-trips a value through the `LowerHex`/`UpperHex` `Display` impls
+            /// back into [`from_str_radix`]. Concrete field crates should call this from a
+            /// `#[test]` fn once a concrete `P` is in scope, e.g.: `#[test] fn
+            /// from_str_radix_and_hex_round_trip() { from_str_radix_test_template::<FrParameters>() }`
+            #[cfg(test)]
+            pub(crate) fn from_str_radix_test_template<P: $FpParameters>() {
+                assert_eq!($Fp::<P>::from_str_radix("0", 10), Ok($Fp::<P>::zero()));
+                assert_eq!($Fp::<P>::from_str_radix("10", 10), Ok($Fp::<P>::from(10u64)));
+                assert_eq!($Fp::<P>::from_str_radix("ff", 16), Ok($Fp::<P>::from(0xffu64)));
+
+                assert_eq!($Fp::<P>::from_str_radix("", 10), Err(()));
+                assert_eq!($Fp::<P>::from_str_radix("01", 10), Err(()));
+                assert_eq!($Fp::<P>::from_str_radix("1g", 16), Err(()));
+                assert_eq!($Fp::<P>::from_str_radix("1", 1), Err(()));
+                assert_eq!($Fp::<P>::from_str_radix("1", 17), Err(()));
+
+                let x = $Fp::<P>::from(0x1234_5678u64);
+                let lower = ark_std::format!("{:x}", x);
+                let upper = ark_std::format!("{:X}", x);
+                assert_eq!(lower.to_uppercase(), upper);
+                assert_eq!($Fp::<P>::from_str_radix(&lower, 16), Ok(x));
+            }
+        }
+
+        /// Exercises [`inverse_ct`](Self::inverse_ct) against zero, one, several round-trip
+        /// values, and `-1`. It also compares each result with [`inverse`](Field::inverse),
+        /// but with the `ct-inverse` feature enabled `inverse` is `inverse_ct` itself, so the
+        /// `ct * x == 1` round-trip checks are what verify the result. Concrete field crates
+        /// should call this from a `#[test]` fn once a concrete `P` is in scope, e.g.:
+        /// `#[test] fn inverse_ct_matches_inverse() { inverse_ct_test_template::<FrParameters>() }`
+        #[cfg(all(test, feature = "ct-inverse"))]
+        pub(crate) fn inverse_ct_test_template<P: $FpParameters>() {
+            assert!($Fp::<P>::zero().inverse_ct().is_none());
+
+            let one = $Fp::<P>::one();
+            assert_eq!(one.inverse_ct(), Some(one));
+
+            let mut x = one;
+            for _ in 0..32 {
+                x += &one;
+                let ct = x.inverse_ct().expect("non-zero element has an inverse");
+                assert_eq!(Some(ct), x.inverse(), "inverse_ct disagrees with inverse");
+                assert_eq!(ct * &x, one, "inverse_ct did not round-trip to one");
+            }
+
+            let neg_one = -one;
+            let ct = neg_one.inverse_ct().expect("non-zero element has an inverse");
+            assert_eq!(ct * &neg_one, one);
         }
 
         impl<P: $FpParameters> Zero for $Fp<P> {
@@ -334,9 +885,37 @@ macro_rules! impl_Fp {
                 temp
             }
 
+            /// Routes through [`mul_assign_adx`](Self::mul_assign_adx)/
+            /// [`mul_assign_portable`](Self::mul_assign_portable) with `other = *self`. This
+            /// gets squaring onto the ADX/BMI2 carry-chain path, but it does not exploit the
+            /// `a == b` cross-term symmetry (`2*a[i]*a[j]` for `i != j` computed once instead
+            /// of twice) that a dedicated squaring kernel would use for the usual ~25-33%
+            /// speedup over a general multiply; that kernel is not implemented here.
+            #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+            fn square_in_place(&mut self) -> &mut Self {
+                let other = *self;
+                if ($limbs == 4 || $limbs == 6)
+                    && std::is_x86_feature_detected!("bmi2")
+                    && std::is_x86_feature_detected!("adx")
+                {
+                    unsafe { self.mul_assign_adx(&other) };
+                } else {
+                    self.mul_assign_portable(&other);
+                }
+                self
+            }
+
+            #[cfg(not(all(feature = "asm", feature = "std", target_arch = "x86_64")))]
             impl_field_square_in_place!($limbs);
 
             #[inline]
+            #[cfg(feature = "ct-inverse")]
+            fn inverse(&self) -> Option<Self> {
+                self.inverse_ct()
+            }
+
+            #[inline]
+            #[cfg(not(feature = "ct-inverse"))]
             fn inverse(&self) -> Option<Self> {
                 if self.is_zero() {
                     None
@@ -426,6 +1005,63 @@ macro_rules! impl_Fp {
             }
 
             impl_field_into_repr!($limbs, $BigIntegerType);
+
+            /// Reduces a big-endian byte string of any length modulo `P::MODULUS`, by Horner's
+            /// method: `acc = acc * 256 + byte` for each successive byte, lifting `256` and
+            /// each byte into the field via the existing `From<u64>` conversions. Unlike
+            /// `from_random_bytes_with_flags`, which only masks and rejects out-of-range
+            /// values, this accepts bytes of arbitrary length and maps them uniformly into the
+            /// field — the primitive hash-to-field needs, and what decoding scalars produced by
+            /// other libraries' minimal byte-array representations requires.
+            fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+                let mut acc = Self::zero();
+                let window = Self::from(256u64);
+                for byte in bytes.iter() {
+                    acc *= &window;
+                    acc += &Self::from(*byte as u64);
+                }
+                acc
+            }
+
+            /// Like [`from_be_bytes_mod_order`](Self::from_be_bytes_mod_order), but for a
+            /// little-endian byte string: the same Horner's-method loop, just walking `bytes`
+            /// from the back instead of reversing it into a freshly allocated buffer first.
+            fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+                let mut acc = Self::zero();
+                let window = Self::from(256u64);
+                for byte in bytes.iter().rev() {
+                    acc *= &window;
+                    acc += &Self::from(*byte as u64);
+                }
+                acc
+            }
+
+            /// Exercises `from_be_bytes_mod_order`/`from_le_bytes_mod_order` on the empty
+            /// input, a single byte, and the same bytes in both orders, checking the two
+            /// agree on reversed input and that a small value round-trips through `into_repr`.
+            /// Concrete field crates should call this from a `#[test]` fn once a concrete `P`
+            /// is in scope, e.g.: `#[test] fn bytes_mod_order_round_trip() {
+            /// bytes_mod_order_test_template::<FrParameters>() }`
+            #[cfg(test)]
+            pub(crate) fn bytes_mod_order_test_template<P: $FpParameters>() {
+                assert_eq!($Fp::<P>::from_be_bytes_mod_order(&[]), $Fp::<P>::zero());
+                assert_eq!($Fp::<P>::from_le_bytes_mod_order(&[]), $Fp::<P>::zero());
+
+                assert_eq!($Fp::<P>::from_be_bytes_mod_order(&[7]), $Fp::<P>::from(7u64));
+                assert_eq!($Fp::<P>::from_le_bytes_mod_order(&[7]), $Fp::<P>::from(7u64));
+
+                let bytes = [0x01u8, 0x02, 0x03, 0x04];
+                let mut reversed = bytes;
+                reversed.reverse();
+                assert_eq!(
+                    $Fp::<P>::from_be_bytes_mod_order(&bytes),
+                    $Fp::<P>::from_le_bytes_mod_order(&reversed),
+                );
+                assert_eq!(
+                    $Fp::<P>::from_be_bytes_mod_order(&bytes),
+                    $Fp::<P>::from(0x01020304u64),
+                );
+            }
         }
 
         impl<P: $FpParameters> FftField for $Fp<P> {
@@ -522,55 +1158,57 @@ macro_rules! impl_Fp {
         impl<P: $FpParameters> FromStr for $Fp<P> {
             type Err = ();
 
-            /// Interpret a string of numbers as a (congruent) prime field element.
+            /// Interpret a string of decimal numbers as a (congruent) prime field element.
             /// Does not accept unnecessary leading zeroes or a blank string.
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if s.is_empty() {
-                    return Err(());
-                }
-
-                if s == "0" {
-                    return Ok(Self::zero());
-                }
-
-                let mut res = Self::zero();
-
-                let ten = Self::from(<Self as PrimeField>::BigInt::from(10));
-
-                let mut first_digit = true;
-
-                for c in s.chars() {
-                    match c.to_digit(10) {
-                        Some(c) => {
-                            if first_digit {
-                                if c == 0 {
-                                    return Err(());
-                                }
+                Self::from_str_radix(s, 10)
+            }
+        }
 
-                                first_digit = false;
-                            }
+        impl<P: $FpParameters> Display for $Fp<P> {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, stringify!($Fp"({})"), self.into_repr())
+            }
+        }
 
-                            res.mul_assign(&ten);
-                            let digit = Self::from(u64::from(c));
-                            res.add_assign(&digit);
-                        },
-                        None => {
-                            return Err(());
-                        },
+        impl<P: $FpParameters> core::fmt::LowerHex for $Fp<P> {
+            /// Emits the canonical big-endian hex of `into_repr()`, the form most other curve
+            /// libraries use for constants and test vectors. Leading zero digits are suppressed
+            /// (printing `"0"` for the zero value) so the output round-trips through
+            /// `from_str_radix`, matching how Rust's own integer `{:x}` formatting behaves.
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                let mut started = false;
+                for limb in self.into_repr().0.iter().rev() {
+                    if started {
+                        write!(f, "{:016x}", limb)?;
+                    } else if *limb != 0 {
+                        write!(f, "{:x}", limb)?;
+                        started = true;
                     }
                 }
-                if !res.is_valid() {
-                    Err(())
-                } else {
-                    Ok(res)
+                if !started {
+                    write!(f, "0")?;
                 }
+                Ok(())
             }
         }
 
-        impl<P: $FpParameters> Display for $Fp<P> {
-            #[inline]
+        impl<P: $FpParameters> core::fmt::UpperHex for $Fp<P> {
             fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-                write!(f, stringify!($Fp"({})"), self.into_repr())
+                let mut started = false;
+                for limb in self.into_repr().0.iter().rev() {
+                    if started {
+                        write!(f, "{:016X}", limb)?;
+                    } else if *limb != 0 {
+                        write!(f, "{:X}", limb)?;
+                        started = true;
+                    }
+                }
+                if !started {
+                    write!(f, "0")?;
+                }
+                Ok(())
             }
         }
 
@@ -658,6 +1296,26 @@ macro_rules! impl_Fp {
         }
 
         impl<'a, P: $FpParameters> MulAssign<&'a Self> for $Fp<P> {
+            // Runtime-dispatched: `mul_assign_adx` requires BMI2 and ADX, which may not be
+            // present on the CPU this binary actually runs on even when the binary itself was
+            // built for x86_64, so the check happens via `is_x86_feature_detected!` on every
+            // call rather than by baking in a compile-time `target_feature` requirement (which
+            // would make the binary either refuse to run on older CPUs, or never take this path
+            // at all on a binary built without those features enabled).
+            #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+            #[inline]
+            fn mul_assign(&mut self, other: &Self) {
+                if ($limbs == 4 || $limbs == 6)
+                    && std::is_x86_feature_detected!("bmi2")
+                    && std::is_x86_feature_detected!("adx")
+                {
+                    unsafe { self.mul_assign_adx(other) };
+                } else {
+                    self.mul_assign_portable(other);
+                }
+            }
+
+            #[cfg(not(all(feature = "asm", feature = "std", target_arch = "x86_64")))]
             impl_field_mul_assign!($limbs);
         }
 
@@ -677,3 +1335,415 @@ macro_rules! impl_Fp {
         }
     }
 }
+
+use crate::biginteger::BigInteger;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The Montgomery constants for a modulus chosen at runtime, analogous to what `FpParameters`
+/// bakes in at compile time for `$Fp<P>`.
+///
+/// Built once via [`DynFpContext::new`] and then shared (by reference) across every [`DynFp`]
+/// reduced against it, so that arithmetic modulo a runtime-supplied prime does not require
+/// generating a new monomorphized field type per modulus.
+pub struct DynFpContext<B: BigInteger> {
+    modulus: B,
+    r: B,
+    r2: B,
+    inv: u64,
+}
+
+impl<B: BigInteger> DynFpContext<B> {
+    /// Derives the Montgomery context for `modulus`, which must be odd and must leave the top
+    /// bit of its representation free (`num_bits() < 64 * B::NUM_LIMBS`) — the same spare-bit
+    /// invariant `REPR_SHAVE_BITS >= 1` guarantees for the compile-time `$Fp<P>` types, and
+    /// required here so that `mul2()` below, which shifts within the fixed limb width and
+    /// drops any carry out of the top limb, can never silently lose a bit while deriving `r`.
+    pub fn new(modulus: B) -> Self {
+        assert!(!modulus.is_even(), "DynFp requires an odd modulus");
+        assert!(
+            modulus.num_bits() < 64 * B::NUM_LIMBS as u32,
+            "DynFp requires a modulus with a spare top bit (num_bits() < 64 * NUM_LIMBS)"
+        );
+
+        // `inv = -modulus^{-1} mod 2^64`, by Newton's iteration on the low limb: each step
+        // doubles the number of correct low bits, so 6 iterations suffice for a 64-bit word.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.as_ref()[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        // `r = 2^(64 * NUM_LIMBS) mod modulus`, built by repeated doubling-and-reducing
+        // starting from `1`; `r2 = r^2 mod modulus` is then just `r` carried through again.
+        let mut r = B::from(1u64);
+        for _ in 0..(64 * B::NUM_LIMBS) {
+            r.mul2();
+            if r >= modulus {
+                r.sub_noborrow(&modulus);
+            }
+        }
+        let mut r2 = r;
+        for _ in 0..(64 * B::NUM_LIMBS) {
+            r2.mul2();
+            if r2 >= modulus {
+                r2.sub_noborrow(&modulus);
+            }
+        }
+
+        Self { modulus, r, r2, inv }
+    }
+}
+
+/// A field element modulo a [`DynFpContext`]'s runtime modulus.
+///
+/// Mirrors `$Fp<P>`: stored internally in Montgomery form (`value = repr * R mod modulus`),
+/// reusing the same `mul_without_reduce`/reduce/`sub_noborrow` shape, but threading the
+/// context through each operation instead of reading it off a `P: FpParameters` type.
+#[derive(Clone, Copy)]
+pub struct DynFp<'a, B: BigInteger> {
+    value: B,
+    ctx: &'a DynFpContext<B>,
+}
+
+impl<'a, B: BigInteger> DynFp<'a, B> {
+    #[inline]
+    pub fn zero(ctx: &'a DynFpContext<B>) -> Self {
+        Self { value: B::from(0u64), ctx }
+    }
+
+    #[inline]
+    pub fn one(ctx: &'a DynFpContext<B>) -> Self {
+        Self { value: ctx.r, ctx }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    pub fn from_repr(repr: B, ctx: &'a DynFpContext<B>) -> Self {
+        let mut x = Self { value: repr, ctx };
+        let r2 = Self { value: ctx.r2, ctx };
+        x.mul_assign(&r2);
+        x
+    }
+
+    pub fn into_repr(&self) -> B {
+        let demontgomerized = self.mul_without_reduce(&B::from(1u64));
+        Self::reduce(demontgomerized, self.ctx)
+    }
+
+    // Same CIOS Montgomery multiplication as `$Fp::mul_without_reduce`/`const_mul`, but over a
+    // runtime-sized limb count and a runtime modulus/`inv` pulled from `self.ctx`.
+    fn mul_without_reduce(&self, other: &B) -> B {
+        let n = B::NUM_LIMBS;
+        let a = self.value.as_ref();
+        let b = other.as_ref();
+        let modulus = self.ctx.modulus.as_ref();
+        let inv = self.ctx.inv;
+
+        let mut r = ark_std::vec![0u64; 2 * n];
+        for i in 0..n {
+            let mut carry: u128 = 0;
+            for j in 0..n {
+                let sum = r[j + i] as u128 + a[i] as u128 * b[j] as u128 + carry;
+                r[j + i] = sum as u64;
+                carry = sum >> 64;
+            }
+            r[n + i] = carry as u64;
+        }
+
+        let mut carry_out = 0u64;
+        for i in 0..n {
+            let k = r[i].wrapping_mul(inv);
+            let mut carry = k as u128 * modulus[0] as u128 + r[i] as u128;
+            carry >>= 64;
+            for j in 1..n {
+                let sum = r[j + i] as u128 + k as u128 * modulus[j] as u128 + carry;
+                r[j + i] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum = r[n + i] as u128 + carry_out as u128 + carry;
+            r[n + i] = sum as u64;
+            carry_out = (sum >> 64) as u64;
+        }
+
+        let mut out = B::from(0u64);
+        out.as_mut().copy_from_slice(&r[n..2 * n]);
+        out
+    }
+
+    #[inline]
+    fn reduce(value: B, ctx: &DynFpContext<B>) -> B {
+        let mut v = value;
+        if v >= ctx.modulus {
+            v.sub_noborrow(&ctx.modulus);
+        }
+        v
+    }
+
+    pub fn neg(&self) -> Self {
+        if self.is_zero() {
+            *self
+        } else {
+            let mut tmp = self.ctx.modulus;
+            tmp.sub_noborrow(&self.value);
+            Self { value: tmp, ctx: self.ctx }
+        }
+    }
+
+    /// Same Guajardo–Kumar–Paar–Pelzl binary-GCD inversion as `$Fp::inverse`, run against the
+    /// runtime modulus in `self.ctx`.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        let one = B::from(1u64);
+        let mut u = self.value;
+        let mut v = self.ctx.modulus;
+        let mut b = Self { value: self.ctx.r2, ctx: self.ctx };
+        let mut c = Self::zero(self.ctx);
+
+        while u != one && v != one {
+            while u.is_even() {
+                u.div2();
+                if b.value.is_even() {
+                    b.value.div2();
+                } else {
+                    b.value.add_nocarry(&self.ctx.modulus);
+                    b.value.div2();
+                }
+            }
+
+            while v.is_even() {
+                v.div2();
+                if c.value.is_even() {
+                    c.value.div2();
+                } else {
+                    c.value.add_nocarry(&self.ctx.modulus);
+                    c.value.div2();
+                }
+            }
+
+            if v < u {
+                u.sub_noborrow(&v);
+                b.sub_assign(&c);
+            } else {
+                v.sub_noborrow(&u);
+                c.sub_assign(&b);
+            }
+        }
+
+        Some(if u == one { b } else { c })
+    }
+}
+
+/// Exercises `DynFp`'s zero/one, `+=`/`-=`/`*=`, and inversion against a caller-supplied odd
+/// modulus with a spare top bit (the invariant [`DynFpContext::new`] now asserts). Concrete
+/// crates should call this from a `#[test]` fn with a real prime of their `BigInteger` type,
+/// e.g.: `#[test] fn dyn_fp_round_trip() { dyn_fp_test_template(BigInteger256::from(...)) }`
+#[cfg(test)]
+pub fn dyn_fp_test_template<B: BigInteger>(modulus: B) {
+    let ctx = DynFpContext::new(modulus);
+
+    let zero = DynFp::zero(&ctx);
+    let one = DynFp::one(&ctx);
+    assert!(zero.is_zero());
+    assert!(!one.is_zero());
+    assert_eq!(zero.into_repr(), B::from(0u64));
+    assert_eq!(one.into_repr(), B::from(1u64));
+
+    let mut two = one;
+    two += &one;
+    assert_eq!(two.into_repr(), B::from(2u64));
+
+    let mut three = two;
+    three += &one;
+    assert_eq!(three.into_repr(), B::from(3u64));
+
+    let mut back_to_two = three;
+    back_to_two -= &one;
+    assert_eq!(back_to_two, two);
+
+    let mut six = two;
+    six *= &three;
+    assert_eq!(six.into_repr(), B::from(6u64));
+
+    let inv = three.inverse().expect("non-zero element has an inverse");
+    let mut product = inv;
+    product *= &three;
+    assert_eq!(product, one);
+
+    assert_eq!(DynFp::from_repr(B::from(5u64), &ctx).into_repr(), B::from(5u64));
+
+    assert!(zero.inverse().is_none());
+    assert_eq!(DynFp::neg(&zero), zero);
+
+    let mut sum = three;
+    sum += &DynFp::neg(&three);
+    assert_eq!(sum, zero);
+}
+
+impl<'a, B: BigInteger> PartialEq for DynFp<'a, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<'a, B: BigInteger> Eq for DynFp<'a, B> {}
+
+// The reference's own borrow gets its own lifetime `'b`, independent of the context lifetime
+// `'a` shared by `self` and `other`'s underlying `DynFp`s — reusing `'a` for both would force
+// every `&other` passed to `+=`/`-=`/`*=` to be borrowed for the full context lifetime.
+impl<'a, 'b, B: BigInteger> AddAssign<&'b Self> for DynFp<'a, B> {
+    fn add_assign(&mut self, other: &'b Self) {
+        debug_assert!(
+            core::ptr::eq(self.ctx, other.ctx),
+            "DynFp operands use different moduli"
+        );
+        self.value.add_nocarry(&other.value);
+        if self.value >= self.ctx.modulus {
+            self.value.sub_noborrow(&self.ctx.modulus);
+        }
+    }
+}
+
+impl<'a, 'b, B: BigInteger> SubAssign<&'b Self> for DynFp<'a, B> {
+    fn sub_assign(&mut self, other: &'b Self) {
+        debug_assert!(
+            core::ptr::eq(self.ctx, other.ctx),
+            "DynFp operands use different moduli"
+        );
+        if other.value > self.value {
+            self.value.add_nocarry(&self.ctx.modulus);
+        }
+        self.value.sub_noborrow(&other.value);
+    }
+}
+
+impl<'a, 'b, B: BigInteger> MulAssign<&'b Self> for DynFp<'a, B> {
+    fn mul_assign(&mut self, other: &'b Self) {
+        debug_assert!(
+            core::ptr::eq(self.ctx, other.ctx),
+            "DynFp operands use different moduli"
+        );
+        let product = self.mul_without_reduce(&other.value);
+        self.value = Self::reduce(product, self.ctx);
+    }
+}
+
+impl<'a, B: BigInteger> fmt::Debug for DynFp<'a, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynFp({:?})", self.into_repr())
+    }
+}
+
+impl<'a, B: BigInteger> Add for DynFp<'a, B> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.add_assign(&other);
+        self
+    }
+}
+
+impl<'a, B: BigInteger> Sub for DynFp<'a, B> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self.sub_assign(&other);
+        self
+    }
+}
+
+impl<'a, B: BigInteger> Mul for DynFp<'a, B> {
+    type Output = Self;
+
+    fn mul(mut self, other: Self) -> Self {
+        self.mul_assign(&other);
+        self
+    }
+}
+
+impl<'a, B: BigInteger> Neg for DynFp<'a, B> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DynFp::neg(&self)
+    }
+}
+
+/// Wires the `*_test_template` helpers scattered through this file up to a concrete field, so
+/// they actually execute under `cargo test` instead of sitting unused. `Fq61Parameters` is a toy
+/// modulus chosen only for this purpose (`2^61 - 1`, a Mersenne prime that fits in a single
+/// `BigInteger64` limb with three spare top bits, which is also what [`dyn_fp_test_template`]
+/// needs); real field crates define their own `FpParameters` and should call these same
+/// templates against it instead of reusing this one.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::biginteger::BigInteger64;
+    use crate::fields::{FftParameters, FpParameters};
+    use ark_std::{One, Zero};
+
+    impl_Fp!(TestFp, TestFpParameters, BigInteger64, BigInteger64, 1);
+
+    pub struct Fq61Parameters;
+
+    impl FftParameters for Fq61Parameters {
+        type BigInt = BigInteger64;
+
+        const TWO_ADICITY: u32 = 1;
+        const TWO_ADIC_ROOT_OF_UNITY: BigInteger64 = BigInteger64([0x1ffffffffffffff7]);
+    }
+
+    impl FpParameters for Fq61Parameters {
+        const MODULUS: BigInteger64 = BigInteger64([0x1fffffffffffffff]);
+        const MODULUS_BITS: u32 = 61;
+        const CAPACITY: u32 = 60;
+        const REPR_SHAVE_BITS: u32 = 3;
+        const R: BigInteger64 = BigInteger64([0x8]);
+        const R2: BigInteger64 = BigInteger64([0x40]);
+        const INV: u64 = 0x2000000000000001;
+        const GENERATOR: BigInteger64 = BigInteger64([0x128]);
+        const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0xfff_ffff_ffff_ffff]);
+        const T: BigInteger64 = BigInteger64([0xfff_ffff_ffff_ffff]);
+        const T_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7ff_ffff_ffff_ffff]);
+    }
+
+    impl TestFpParameters for Fq61Parameters {}
+
+    #[test]
+    fn batch_inverse_matches_per_element() {
+        TestFp::<Fq61Parameters>::batch_inverse_test_template::<Fq61Parameters>();
+    }
+
+    #[test]
+    fn from_str_radix_and_hex_round_trip() {
+        TestFp::<Fq61Parameters>::from_str_radix_test_template::<Fq61Parameters>();
+    }
+
+    #[test]
+    fn bytes_mod_order_round_trip() {
+        TestFp::<Fq61Parameters>::bytes_mod_order_test_template::<Fq61Parameters>();
+    }
+
+    #[test]
+    #[cfg(feature = "ct-inverse")]
+    fn inverse_ct_matches_inverse() {
+        inverse_ct_test_template::<Fq61Parameters>();
+    }
+
+    #[test]
+    #[cfg(all(feature = "asm", feature = "std", target_arch = "x86_64"))]
+    fn mul_assign_adx_matches_portable() {
+        TestFp::<Fq61Parameters>::mul_assign_adx_test_template::<Fq61Parameters>();
+    }
+
+    #[test]
+    fn dyn_fp_round_trip() {
+        dyn_fp_test_template(BigInteger64([0x1fffffffffffffff]));
+    }
+}